@@ -3,7 +3,7 @@
 //! `account` gives access to the Account API and the various endpoints associated with it.
 //! This allows you to obtain account information either by account UUID or in bulk (all accounts).
 
-use crate::utils::{CBAdvError, Result, Signer};
+use crate::utils::{CBAdvError, Paginator, Result, Signer};
 use async_recursion::async_recursion;
 use serde::{Deserialize, Serialize};
 
@@ -182,4 +182,29 @@ impl AccountAPI {
             Err(error) => Err(error),
         }
     }
+
+    /// Streams every account, transparently following the cursor until the API reports no
+    /// more pages are available. This is a convenience wrapper over [`AccountAPI::get_bulk`]
+    /// that lets callers `while let Some(account) = stream.next().await` instead of managing
+    /// `ListAccountsParams::cursor` by hand.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional parameters, should default to None unless you want additional control.
+    pub fn stream(&self, params: Option<ListAccountsParams>) -> Paginator<Account> {
+        let signer = self.signer.clone();
+        let (limit, starting_cursor) = match params {
+            Some(p) => (p.limit, p.cursor),
+            None => (None, None),
+        };
+
+        Paginator::new_with_cursor(starting_cursor, move |cursor| {
+            let api = AccountAPI::new(signer.clone());
+            let params = ListAccountsParams { limit, cursor };
+            async move {
+                let listed = api.get_bulk(&params).await?;
+                Ok((listed.accounts, listed.has_next, listed.cursor))
+            }
+        })
+    }
 }
@@ -4,7 +4,8 @@
 //! This is the primary method of accessing the endpoints and handles all of the configurations and
 //! negotiations for the user.
 
-use crate::utils::Signer;
+use crate::utils::{CBAdvError, RequestLayer, Result, Signer};
+use std::time::Duration;
 
 use crate::account::AccountAPI;
 use crate::fee::FeeAPI;
@@ -14,10 +15,6 @@ use crate::product::ProductAPI;
 /// Represents a Client for the API.
 #[allow(dead_code)]
 pub struct Client {
-    /// API Key provided by the service to the user.
-    api_key: String,
-    /// API Secret provided by the service to the user.
-    api_secret: String,
     /// Responsible for making all HTTP requests.
     signer: Signer,
     /// Gives access to the Account API.
@@ -38,15 +35,20 @@ impl Client {
     /// * `key` - A string that holds the key for the API service.
     /// * `secret` - A string that holds the secret for the API service.
     pub fn new(key: String, secret: String) -> Self {
-        let signer = Signer::new(key.clone(), secret.clone());
+        ClientBuilder::new()
+            .credentials(key, secret)
+            .build()
+            .expect("default ClientBuilder should never fail to build")
+    }
+
+    /// Wraps a configured Signer in the various APIs.
+    fn from_signer(signer: Signer) -> Self {
         let account = AccountAPI::new(signer.clone());
         let product = ProductAPI::new(signer.clone());
         let fee = FeeAPI::new(signer.clone());
         let order = OrderAPI::new(signer.clone());
 
         Self {
-            api_key: String::from(key),
-            api_secret: String::from(secret),
             signer,
             account,
             product,
@@ -65,3 +67,132 @@ impl Client {
 pub fn new(key: String, secret: String) -> Client {
     Client::new(key, secret)
 }
+
+/// Credentials a [`ClientBuilder`] authenticates requests with.
+enum Credentials {
+    /// Legacy HMAC API key/secret pair.
+    Legacy { key: String, secret: String },
+    /// EC Cloud API key, authenticated via ES256 JWT.
+    Cloud {
+        key_name: String,
+        ec_private_key_pem: String,
+    },
+    /// No credentials; only public endpoints may be called.
+    Public,
+}
+
+/// Builds a [`Client`], configuring the base URL, request timeout, and credentials.
+///
+/// Defaults to the production API host, a 30 second request timeout, and no credentials
+/// (public/keyless mode). Use [`ClientBuilder::credentials`] or
+/// [`ClientBuilder::cloud_credentials`] to authenticate, and [`ClientBuilder::base_url`] to
+/// target a sandbox/mock host.
+pub struct ClientBuilder {
+    base_url: Option<String>,
+    timeout: Duration,
+    credentials: Credentials,
+    layers: Option<Vec<Box<dyn RequestLayer>>>,
+}
+
+impl ClientBuilder {
+    /// Creates a new ClientBuilder with the default base URL, a 30 second timeout, no
+    /// credentials, and the default layer stack (see [`Signer::with_layers`]).
+    pub fn new() -> Self {
+        Self {
+            base_url: None,
+            timeout: Duration::from_secs(30),
+            credentials: Credentials::Public,
+            layers: None,
+        }
+    }
+
+    /// Overrides the base URL requests are sent to, e.g. to target a sandbox/mock host.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - Base URL the API is served from, with no trailing slash.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Sets the request timeout used by the underlying HTTP client.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Maximum duration to wait for a request to complete.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Authenticates with a legacy HMAC API key/secret pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string that holds the key for the API service.
+    /// * `secret` - A string that holds the secret for the API service.
+    pub fn credentials(mut self, key: String, secret: String) -> Self {
+        self.credentials = Credentials::Legacy { key, secret };
+        self
+    }
+
+    /// Authenticates with an EC Cloud API key (ES256 JWT authentication).
+    ///
+    /// # Arguments
+    ///
+    /// * `key_name` - The Cloud API key name, e.g. "organizations/.../apiKeys/...".
+    /// * `ec_private_key_pem` - The EC private key issued alongside `key_name`, as PKCS#8 PEM.
+    pub fn cloud_credentials(mut self, key_name: String, ec_private_key_pem: String) -> Self {
+        self.credentials = Credentials::Cloud {
+            key_name,
+            ec_private_key_pem,
+        };
+        self
+    }
+
+    /// Replaces the layer stack requests are run through, e.g. to add a rate limiter or
+    /// logging on top of (or instead of) the default retry policy. See
+    /// [`Signer::with_layers`].
+    ///
+    /// # Arguments
+    ///
+    /// * `layers` - Ordered stack of layers to run each request through.
+    pub fn layers(mut self, layers: Vec<Box<dyn RequestLayer>>) -> Self {
+        self.layers = Some(layers);
+        self
+    }
+
+    /// Builds the Client. Only fails if the configured credentials or timeout are invalid.
+    pub fn build(self) -> Result<Client> {
+        let http = reqwest::Client::builder()
+            .timeout(self.timeout)
+            .build()
+            .map_err(|_| CBAdvError::Unknown("failed to build HTTP client".to_string()))?;
+
+        let mut signer = match self.credentials {
+            Credentials::Legacy { key, secret } => Signer::new_with_client(key, secret, http),
+            Credentials::Cloud {
+                key_name,
+                ec_private_key_pem,
+            } => Signer::new_cloud_with_client(key_name, &ec_private_key_pem, http)?,
+            Credentials::Public => Signer::new_public_with_client(http),
+        };
+
+        if let Some(base_url) = self.base_url {
+            signer = signer.with_base_url(base_url);
+        }
+
+        if let Some(layers) = self.layers {
+            signer = signer.with_layers(layers);
+        }
+
+        Ok(Client::from_signer(signer))
+    }
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
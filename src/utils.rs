@@ -6,12 +6,28 @@
 //! reporting errors that may occur.
 
 use crate::time;
+use async_trait::async_trait;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use base64::Engine as _;
+use futures::stream::Stream;
 use hex;
 use hmac::{Hmac, Mac};
+use rand::{Rng, RngCore};
 use reqwest::{header, Method, Response, StatusCode};
+use ring::rand::SystemRandom;
+use ring::signature::{EcdsaKeyPair, ECDSA_P256_SHA256_FIXED_SIGNING};
 use serde::Serialize;
+use serde_json::json;
 use sha2::Sha256;
+use std::collections::VecDeque;
 use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tokio::time::sleep;
 
 /// Types of errors that can occur.
 #[derive(Debug)]
@@ -26,10 +42,34 @@ pub enum CBAdvError {
     NothingToDo(String),
     /// Unable to locate resource.
     NotFound(String),
+    /// A signed endpoint was hit by a Signer with no credentials configured (public/keyless
+    /// mode).
+    AuthRequired(String),
     /// General unknown error.
     Unknown(String),
 }
 
+/// Method of authenticating requests made by a [`Signer`].
+#[derive(Clone)]
+enum AuthMode {
+    /// Legacy HMAC-SHA256 signing using an API key/secret pair.
+    Legacy { api_secret: String },
+    /// ES256 JWT signing using an EC Cloud API key, as issued by the CDP portal.
+    Cloud {
+        key_name: String,
+        key_pair: Arc<EcdsaKeyPair>,
+    },
+}
+
+impl fmt::Debug for AuthMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AuthMode::Legacy { .. } => write!(f, "Legacy"),
+            AuthMode::Cloud { key_name, .. } => write!(f, "Cloud({})", key_name),
+        }
+    }
+}
+
 impl fmt::Display for CBAdvError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -39,6 +79,9 @@ impl fmt::Display for CBAdvError {
             CBAdvError::NotFound(value) => write!(f, "could not find: {}", value),
             CBAdvError::BadStatus(value) => write!(f, "non-zero status occurred: {}", value),
             CBAdvError::BadConnection(value) => write!(f, "could not connect: {}", value),
+            CBAdvError::AuthRequired(value) => {
+                write!(f, "credentials required to call signed endpoint: {}", value)
+            }
         }
     }
 }
@@ -50,41 +93,446 @@ type HmacSha256 = Hmac<Sha256>;
 /// Root URI for the API service.
 const ROOT_URI: &str = "https://api.coinbase.com";
 
-/// Creates and signs HTTP Requests to the API.
+/// The outcome of a layer's [`RequestLayer::after`] hook: either accept the attempt as final,
+/// or retry the request after the given delay.
+pub enum LayerOutcome {
+    /// Accept the result as final.
+    Done,
+    /// Retry the request after the given delay.
+    Retry(Duration),
+}
+
+/// Describes the request a [`RequestLayer`] is wrapping, given to both of its hooks.
 #[derive(Debug, Clone)]
+pub struct RequestCtx {
+    /// HTTP Method of the request.
+    pub method: Method,
+    /// Resource being accessed.
+    pub resource: String,
+}
+
+/// A single step in the request middleware stack that a [`Signer`] runs every request
+/// through. Layers run in the order they were pushed: `before` fires prior to sending the
+/// HTTP request (e.g. to throttle), and `after` fires once a response or transport error is
+/// available, deciding whether the attempt should be retried.
+#[async_trait]
+pub trait RequestLayer: Send + Sync {
+    /// Called before the request is sent.
+    async fn before(&self, _ctx: &RequestCtx) {}
+
+    /// Called after a response/error is obtained. If more than one layer requests a retry,
+    /// the longest requested delay wins.
+    async fn after(
+        &self,
+        _ctx: &RequestCtx,
+        _attempt: u32,
+        _result: &std::result::Result<Response, reqwest::Error>,
+    ) -> LayerOutcome {
+        LayerOutcome::Done
+    }
+}
+
+/// Throttles requests to a configured rate using a single token bucket shared by every
+/// request; the rate is not tracked or varied per endpoint.
+pub struct RateLimitLayer {
+    capacity: f64,
+    refill_per_sec: f64,
+    state: Mutex<(f64, Instant)>,
+}
+
+impl RateLimitLayer {
+    /// Floor applied to `requests_per_second` so a misconfigured zero/negative rate throttles
+    /// hard instead of making `before`'s computed wait overflow `Duration::from_secs_f64`.
+    const MIN_REQUESTS_PER_SEC: f64 = 0.01;
+
+    /// Ceiling applied to `before`'s computed wait so a near-zero effective rate waits a long
+    /// time rather than passing an unbounded float into `Duration::from_secs_f64`.
+    const MAX_WAIT: Duration = Duration::from_secs(3600);
+
+    /// Creates a new token-bucket rate limiter.
+    ///
+    /// `requests_per_second` is clamped to [`Self::MIN_REQUESTS_PER_SEC`] so a misconfigured
+    /// zero or negative rate (e.g. an unset env default) throttles to one request per ~100
+    /// seconds instead of panicking.
+    ///
+    /// # Arguments
+    ///
+    /// * `requests_per_second` - Sustained request rate to allow; also used as the bucket's
+    /// burst capacity, which is never less than one request.
+    pub fn new(requests_per_second: f64) -> Self {
+        let refill_per_sec = requests_per_second.max(Self::MIN_REQUESTS_PER_SEC);
+        let capacity = refill_per_sec.max(1.0);
+        Self {
+            capacity,
+            refill_per_sec,
+            state: Mutex::new((capacity, Instant::now())),
+        }
+    }
+}
+
+#[async_trait]
+impl RequestLayer for RateLimitLayer {
+    async fn before(&self, _ctx: &RequestCtx) {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().await;
+                let now = Instant::now();
+                let elapsed = now.duration_since(state.1).as_secs_f64();
+                state.0 = (state.0 + elapsed * self.refill_per_sec).min(self.capacity);
+                state.1 = now;
+
+                if state.0 >= 1.0 {
+                    state.0 -= 1.0;
+                    None
+                } else {
+                    let seconds = (1.0 - state.0) / self.refill_per_sec;
+                    Some(Duration::from_secs_f64(seconds).min(Self::MAX_WAIT))
+                }
+            };
+
+            match wait {
+                Some(delay) => sleep(delay).await,
+                None => break,
+            }
+        }
+    }
+}
+
+/// Retries requests that fail with a 429 or a transient 5xx, using exponential backoff with
+/// jitter. Honors the `Retry-After` header when present.
+pub struct RetryLayer {
+    max_retries: u32,
+    base_delay: Duration,
+}
+
+impl RetryLayer {
+    /// Creates a new retry layer.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_retries` - Maximum number of retry attempts after the initial request.
+    /// * `base_delay` - Base delay used for exponential backoff between retries.
+    pub fn new(max_retries: u32, base_delay: Duration) -> Self {
+        Self {
+            max_retries,
+            base_delay,
+        }
+    }
+
+    /// Computes the exponential backoff delay (with jitter) for a given attempt.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exp = self.base_delay * 2u32.saturating_pow(attempt);
+        let jitter = Duration::from_millis(rand::thread_rng().gen_range(0..50));
+        exp + jitter
+    }
+
+    /// Reads the `Retry-After` header off a response, if present.
+    fn retry_after(response: &Response) -> Option<Duration> {
+        response
+            .headers()
+            .get(header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok())
+            .map(Duration::from_secs)
+    }
+}
+
+#[async_trait]
+impl RequestLayer for RetryLayer {
+    async fn after(
+        &self,
+        _ctx: &RequestCtx,
+        attempt: u32,
+        result: &std::result::Result<Response, reqwest::Error>,
+    ) -> LayerOutcome {
+        if attempt >= self.max_retries {
+            return LayerOutcome::Done;
+        }
+
+        let delay = match result {
+            Ok(response) if response.status() == StatusCode::TOO_MANY_REQUESTS => {
+                Some(Self::retry_after(response).unwrap_or_else(|| self.backoff(attempt)))
+            }
+            Ok(response) if response.status().is_server_error() => Some(self.backoff(attempt)),
+            Ok(_) => None,
+            Err(error) if error.is_timeout() || error.is_connect() => Some(self.backoff(attempt)),
+            Err(_) => None,
+        };
+
+        match delay {
+            Some(delay) => LayerOutcome::Retry(delay),
+            None => LayerOutcome::Done,
+        }
+    }
+}
+
+/// Logs each request attempt and its outcome at `debug` level via the `log` facade, so
+/// embedding applications can filter or redirect output with their logger of choice.
+pub struct LoggingLayer;
+
+#[async_trait]
+impl RequestLayer for LoggingLayer {
+    async fn before(&self, ctx: &RequestCtx) {
+        log::debug!("-> {} {}", ctx.method, ctx.resource);
+    }
+
+    async fn after(
+        &self,
+        ctx: &RequestCtx,
+        attempt: u32,
+        result: &std::result::Result<Response, reqwest::Error>,
+    ) -> LayerOutcome {
+        match result {
+            Ok(response) => log::debug!(
+                "<- {} {} ({}) attempt {}",
+                ctx.method,
+                ctx.resource,
+                response.status(),
+                attempt + 1
+            ),
+            Err(error) => log::debug!(
+                "<- {} {} failed: {} (attempt {})",
+                ctx.method,
+                ctx.resource,
+                error,
+                attempt + 1
+            ),
+        }
+        LayerOutcome::Done
+    }
+}
+
+/// Creates and signs HTTP Requests to the API.
+#[derive(Clone)]
 pub struct Signer {
-    /// API Key provided by the service.
+    /// API Key (or Cloud API key name) provided by the service. Empty in public (keyless) mode.
     pub api_key: String,
-    /// API Secret provided by the service.
-    api_secret: String,
+    /// Method used to authenticate requests, or `None` for public (keyless) mode.
+    auth: Option<AuthMode>,
     /// Wrapped client that is responsible for making the requests.
     client: reqwest::Client,
+    /// Stack of layers requests are run through, in order (retry, rate limiting, logging, etc.).
+    layers: Arc<Vec<Box<dyn RequestLayer>>>,
+    /// Base URL the API is served from, e.g. to target a sandbox/mock host.
+    base_url: String,
+}
+
+impl fmt::Debug for Signer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Signer")
+            .field("api_key", &self.api_key)
+            .field("auth", &self.auth)
+            .field("layers", &self.layers.len())
+            .finish()
+    }
 }
 
 /// Responsible for signing and sending HTTP requests.
 impl Signer {
-    /// Creates a new instance of Signer.
+    /// Creates a new instance of Signer using legacy HMAC API keys.
     ///
     /// # Arguments
     ///
     /// * `api_key` - A string that holds the key for the API service.
     /// * `api_secret` - A string that holds the secret for the API service.
     pub fn new(api_key: String, api_secret: String) -> Self {
+        Self::new_with_client(api_key, api_secret, reqwest::Client::new())
+    }
+
+    /// Creates a new instance of Signer using legacy HMAC API keys and a caller-provided HTTP
+    /// client (e.g. one built with a custom timeout).
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - A string that holds the key for the API service.
+    /// * `api_secret` - A string that holds the secret for the API service.
+    /// * `client` - The `reqwest::Client` used to make requests.
+    pub(crate) fn new_with_client(
+        api_key: String,
+        api_secret: String,
+        client: reqwest::Client,
+    ) -> Self {
         Self {
             api_key,
-            api_secret,
-            client: reqwest::Client::new(),
+            auth: Some(AuthMode::Legacy { api_secret }),
+            client,
+            layers: Arc::new(Self::default_layers()),
+            base_url: ROOT_URI.to_string(),
+        }
+    }
+
+    /// Creates a new instance of Signer using an EC Cloud API key (ES256 JWT authentication).
+    ///
+    /// # Arguments
+    ///
+    /// * `key_name` - The Cloud API key name, e.g. "organizations/.../apiKeys/...".
+    /// * `ec_private_key_pem` - The EC private key issued alongside `key_name`, as PKCS#8 PEM.
+    pub fn new_cloud(key_name: String, ec_private_key_pem: &str) -> Result<Self> {
+        Self::new_cloud_with_client(key_name, ec_private_key_pem, reqwest::Client::new())
+    }
+
+    /// Creates a new instance of Signer using an EC Cloud API key and a caller-provided HTTP
+    /// client (e.g. one built with a custom timeout).
+    ///
+    /// # Arguments
+    ///
+    /// * `key_name` - The Cloud API key name, e.g. "organizations/.../apiKeys/...".
+    /// * `ec_private_key_pem` - The EC private key issued alongside `key_name`, as PKCS#8 PEM.
+    /// * `client` - The `reqwest::Client` used to make requests.
+    pub(crate) fn new_cloud_with_client(
+        key_name: String,
+        ec_private_key_pem: &str,
+        client: reqwest::Client,
+    ) -> Result<Self> {
+        let key_pair = Self::parse_ec_private_key(ec_private_key_pem)?;
+        Ok(Self {
+            api_key: key_name.clone(),
+            auth: Some(AuthMode::Cloud {
+                key_name,
+                key_pair: Arc::new(key_pair),
+            }),
+            client,
+            layers: Arc::new(Self::default_layers()),
+            base_url: ROOT_URI.to_string(),
+        })
+    }
+
+    /// Creates a keyless Signer restricted to public (unauthenticated) endpoints.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The `reqwest::Client` used to make requests.
+    pub(crate) fn new_public_with_client(client: reqwest::Client) -> Self {
+        Self {
+            api_key: String::new(),
+            auth: None,
+            client,
+            layers: Arc::new(Self::default_layers()),
+            base_url: ROOT_URI.to_string(),
+        }
+    }
+
+    /// Overrides the base URL requests are sent to, e.g. to target a sandbox/mock host.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - Base URL the API is served from, with no trailing slash.
+    pub(crate) fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// The layer stack new Signers are created with: a modest retry policy so callers get
+    /// resilient requests without any configuration.
+    fn default_layers() -> Vec<Box<dyn RequestLayer>> {
+        vec![Box::new(RetryLayer::new(3, Duration::from_millis(200)))]
+    }
+
+    /// Replaces the layer stack requests are run through, e.g. to add a [`RateLimitLayer`] or
+    /// a [`LoggingLayer`] on top of the default [`RetryLayer`].
+    ///
+    /// # Arguments
+    ///
+    /// * `layers` - Ordered stack of layers to run each request through.
+    pub fn with_layers(mut self, layers: Vec<Box<dyn RequestLayer>>) -> Self {
+        self.layers = Arc::new(layers);
+        self
+    }
+
+    /// Parses a PEM-encoded EC private key into a P-256 ECDSA key pair suitable for ES256
+    /// JWT signing. Only PKCS#8 keys (`BEGIN PRIVATE KEY`) are accepted; a SEC1 key
+    /// (`BEGIN EC PRIVATE KEY`) fails with [`CBAdvError::BadParse`].
+    fn parse_ec_private_key(pem: &str) -> Result<EcdsaKeyPair> {
+        let der: String = pem
+            .lines()
+            .filter(|line| !line.starts_with("-----"))
+            .collect();
+        let der = STANDARD
+            .decode(der.trim())
+            .map_err(|_| CBAdvError::BadParse("EC private key PEM".to_string()))?;
+
+        let rng = SystemRandom::new();
+        EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, &der, &rng)
+            .map_err(|_| CBAdvError::BadParse("EC private key".to_string()))
+    }
+
+    /// Creates the authentication headers for a signed request, dispatching to the signing
+    /// scheme matching this Signer's [`AuthMode`]. Errors with [`CBAdvError::AuthRequired`] if
+    /// this Signer has no credentials configured (public/keyless mode).
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - HTTP Method as to which action to perform (GET, POST, etc.).
+    /// * `resource` - A string slice representing the resource that is being accessed.
+    /// * `body` - A string representing a body data.
+    fn get_auth_headers(
+        &self,
+        method: Method,
+        resource: &str,
+        body: &str,
+    ) -> Result<header::HeaderMap> {
+        match &self.auth {
+            Some(AuthMode::Legacy { api_secret }) => Ok(Self::get_http_signature(
+                &self.api_key,
+                api_secret,
+                method,
+                resource,
+                body,
+            )),
+            Some(AuthMode::Cloud { key_name, key_pair }) => {
+                let host = Self::host_of(&self.base_url);
+                let jwt = Self::build_jwt(key_name, key_pair, host, method, resource)?;
+                let mut headers = header::HeaderMap::new();
+                let value = format!("Bearer {}", jwt);
+                headers.insert(
+                    header::AUTHORIZATION,
+                    value
+                        .parse()
+                        .map_err(|_| CBAdvError::BadParse("authorization header".to_string()))?,
+                );
+                Ok(headers)
+            }
+            None => Err(CBAdvError::AuthRequired(resource.to_string())),
+        }
+    }
+
+    /// Creates the authentication headers for a public (optionally authenticated) request.
+    /// Unlike [`Signer::get_auth_headers`], this never errors when no credentials are
+    /// configured; it simply omits the auth headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - HTTP Method as to which action to perform (GET, POST, etc.).
+    /// * `resource` - A string slice representing the resource that is being accessed.
+    /// * `body` - A string representing a body data.
+    fn get_optional_auth_headers(
+        &self,
+        method: Method,
+        resource: &str,
+        body: &str,
+    ) -> Result<header::HeaderMap> {
+        match self.auth {
+            Some(_) => self.get_auth_headers(method, resource, body),
+            None => Ok(header::HeaderMap::new()),
         }
     }
 
-    /// Creates the signature headers for a request.
+    /// Creates the legacy HMAC-SHA256 signature headers for a request.
     ///
     /// # Arguments
     ///
+    /// * `api_key` - The API key identifying which secret the signature was made with.
+    /// * `api_secret` - The API secret used to key the HMAC.
     /// * `method` - HTTP Method as to which action to perform (GET, POST, etc.).
     /// * `resource` - A string slice representing the resource that is being accessed.
     /// * `body` - A string representing a body data.
-    fn get_http_signature(&self, method: Method, resource: &str, body: &str) -> header::HeaderMap {
+    fn get_http_signature(
+        api_key: &str,
+        api_secret: &str,
+        method: Method,
+        resource: &str,
+        body: &str,
+    ) -> header::HeaderMap {
         // Timestamp of the request, must be +/- 30 seconds of remote system.
         let timestamp = time::now().to_string();
 
@@ -92,7 +540,7 @@ impl Signer {
         let prehash = format!("{}{}{}{}", timestamp, method, resource, body);
 
         // Create the signature.
-        let mut mac = HmacSha256::new_from_slice(self.api_secret.as_bytes())
+        let mut mac = HmacSha256::new_from_slice(api_secret.as_bytes())
             .expect("Failed to generate a signature.");
         mac.update(prehash.as_bytes());
         let signature = mac.finalize();
@@ -100,13 +548,80 @@ impl Signer {
 
         // Load the signature into the header map.
         let mut headers = header::HeaderMap::new();
-        headers.insert("CB-ACCESS-KEY", self.api_key.parse().unwrap());
+        headers.insert("CB-ACCESS-KEY", api_key.parse().unwrap());
         headers.insert("CB-ACCESS-SIGN", sign.parse().unwrap());
         headers.insert("CB-ACCESS-TIMESTAMP", timestamp.parse().unwrap());
         headers
     }
 
-    /// Creates the signature for a websocket request.
+    /// Builds a short-lived ES256 JWT authorizing a single request, as required by Cloud API
+    /// keys in place of the legacy header triple.
+    ///
+    /// # Arguments
+    ///
+    /// * `key_name` - The Cloud API key name, used as the `kid`/`sub` claim.
+    /// * `key_pair` - The EC key pair backing `key_name`, used to sign the JWT.
+    /// * `method` - HTTP Method the JWT is scoped to.
+    /// * `resource` - The resource path the JWT is scoped to, must match the request exactly.
+    fn build_jwt(
+        key_name: &str,
+        key_pair: &EcdsaKeyPair,
+        host: &str,
+        method: Method,
+        resource: &str,
+    ) -> Result<String> {
+        let now = time::now();
+
+        let mut nonce_bytes = [0u8; 16];
+        rand::thread_rng().fill_bytes(&mut nonce_bytes);
+        let nonce = hex::encode(nonce_bytes);
+
+        let header = json!({
+            "alg": "ES256",
+            "kid": key_name,
+            "typ": "JWT",
+            "nonce": nonce,
+        });
+        let claims = json!({
+            "sub": key_name,
+            "iss": "cdp",
+            "nbf": now,
+            "exp": now + 120,
+            "uri": format!("{} {}{}", method, host, resource),
+        });
+
+        let signing_input = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(header.to_string()),
+            URL_SAFE_NO_PAD.encode(claims.to_string()),
+        );
+
+        let rng = SystemRandom::new();
+        let signature = key_pair
+            .sign(&rng, signing_input.as_bytes())
+            .map_err(|_| CBAdvError::Unknown("failed to sign JWT".to_string()))?;
+
+        Ok(format!(
+            "{}.{}",
+            signing_input,
+            URL_SAFE_NO_PAD.encode(signature.as_ref()),
+        ))
+    }
+
+    /// Strips the scheme from a base URL, leaving just the host (and any path prefix), for use
+    /// in the Cloud JWT `uri` claim, which Coinbase expects without a scheme.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - A base URL such as `https://api.coinbase.com`.
+    fn host_of(base_url: &str) -> &str {
+        base_url
+            .split_once("://")
+            .map(|(_, rest)| rest)
+            .unwrap_or(base_url)
+    }
+
+    /// Creates the signature for a websocket request. Only supported for legacy HMAC API keys.
     ///
     /// # Arguments
     ///
@@ -118,25 +633,128 @@ impl Signer {
         timestamp: &str,
         channel: &str,
         product_ids: &Vec<String>,
-    ) -> String {
+    ) -> Result<String> {
+        let api_secret = match &self.auth {
+            Some(AuthMode::Legacy { api_secret }) => api_secret,
+            Some(AuthMode::Cloud { .. }) => {
+                return Err(CBAdvError::Unknown(
+                    "websocket signing requires a legacy HMAC API key".to_string(),
+                ))
+            }
+            None => {
+                return Err(CBAdvError::AuthRequired(
+                    "websocket subscription".to_string(),
+                ))
+            }
+        };
+
         // Pre-hash, combines all of the request data.
         let prehash = format!("{}{}{}", timestamp, channel, product_ids.join(","));
 
         // Create the signature.
-        let mut mac = HmacSha256::new_from_slice(self.api_secret.as_bytes())
+        let mut mac = HmacSha256::new_from_slice(api_secret.as_bytes())
             .expect("Failed to generate a signature.");
         mac.update(prehash.as_bytes());
         let signature = mac.finalize();
-        hex::encode(signature.into_bytes())
+        Ok(hex::encode(signature.into_bytes()))
     }
 
-    /// Performs a HTTP GET Request.
+    /// Runs a request-building closure through the layer stack, retrying as layers request,
+    /// then maps the final response/error into a [`Result`].
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - Describes the request being made, passed to each layer.
+    /// * `build` - Builds a fresh `RequestBuilder` for each attempt (requests can't be resent
+    /// once sent, so this is called again on every retry).
+    async fn run_through_layers<F>(&self, ctx: RequestCtx, mut build: F) -> Result<Response>
+    where
+        F: FnMut() -> reqwest::RequestBuilder,
+    {
+        let mut attempt = 0u32;
+        loop {
+            for layer in self.layers.iter() {
+                layer.before(&ctx).await;
+            }
+
+            let result = build().send().await;
+
+            let mut outcome = LayerOutcome::Done;
+            for layer in self.layers.iter() {
+                if let LayerOutcome::Retry(delay) = layer.after(&ctx, attempt, &result).await {
+                    outcome = match outcome {
+                        LayerOutcome::Retry(current) if current >= delay => {
+                            LayerOutcome::Retry(current)
+                        }
+                        _ => LayerOutcome::Retry(delay),
+                    };
+                }
+            }
+
+            match outcome {
+                LayerOutcome::Retry(delay) => {
+                    attempt += 1;
+                    sleep(delay).await;
+                    continue;
+                }
+                LayerOutcome::Done => {
+                    return match result {
+                        Ok(value) => match value.status() {
+                            StatusCode::OK => Ok(value),
+                            _ => {
+                                let code = format!("Status Code: {}", value.status().as_u16());
+                                match value.text().await {
+                                    Ok(text) => {
+                                        Err(CBAdvError::BadStatus(format!("{}, {}", code, text)))
+                                    }
+                                    Err(_) => Err(CBAdvError::BadStatus(format!(
+                                        "{}, could not parse error message",
+                                        code
+                                    ))),
+                                }
+                            }
+                        },
+                        Err(_) => Err(CBAdvError::Unknown(format!(
+                            "{} request to API",
+                            ctx.method
+                        ))),
+                    };
+                }
+            }
+        }
+    }
+
+    /// Performs a HTTP GET Request against a signed endpoint. Errors with
+    /// [`CBAdvError::AuthRequired`] if this Signer has no credentials configured.
     ///
     /// # Arguments
     ///
     /// * `resource` - A string representing the resource that is being accessed.
     /// * `params` - A string containing options / parameters for the URL.
     pub async fn get(&self, resource: &str, params: &str) -> Result<Response> {
+        let headers = self.get_auth_headers(Method::GET, resource, "")?;
+        self.send_get(resource, params, headers).await
+    }
+
+    /// Performs a HTTP GET Request against a public endpoint, attaching credentials if
+    /// configured but never requiring them.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource` - A string representing the resource that is being accessed.
+    /// * `params` - A string containing options / parameters for the URL.
+    pub async fn get_public(&self, resource: &str, params: &str) -> Result<Response> {
+        let headers = self.get_optional_auth_headers(Method::GET, resource, "")?;
+        self.send_get(resource, params, headers).await
+    }
+
+    /// Builds the URL for `resource`/`params` and sends a GET request through the layer stack.
+    async fn send_get(
+        &self,
+        resource: &str,
+        params: &str,
+        headers: header::HeaderMap,
+    ) -> Result<Response> {
         // Add the '?' to the beginning of the parameters if not empty.
         let prefix = match params.is_empty() {
             true => "",
@@ -145,31 +763,18 @@ impl Signer {
 
         // Create the full URL being accessed.
         let target = format!("{}{}", prefix, params);
-        let url = format!("{}{}{}", ROOT_URI, resource, target);
+        let url = format!("{}{}{}", self.base_url, resource, target);
 
-        // Create the signature and submit the request.
-        let headers = self.get_http_signature(Method::GET, resource, &"".to_string());
-
-        let result = self.client.get(url).headers(headers).send().await;
-        match result {
-            Ok(value) => match value.status() {
-                StatusCode::OK => Ok(value),
-                _ => {
-                    let code = format!("Status Code: {}", value.status().as_u16());
-                    match value.text().await {
-                        Ok(text) => Err(CBAdvError::BadStatus(format!("{}, {}", code, text))),
-                        Err(_) => Err(CBAdvError::BadStatus(format!(
-                            "{}, could not parse error message",
-                            code
-                        ))),
-                    }
-                }
-            },
-            Err(_) => Err(CBAdvError::Unknown("GET request to API".to_string())),
-        }
+        let ctx = RequestCtx {
+            method: Method::GET,
+            resource: resource.to_string(),
+        };
+        self.run_through_layers(ctx, || self.client.get(&url).headers(headers.clone()))
+            .await
     }
 
-    /// Performs a HTTP POST Request.
+    /// Performs a HTTP POST Request against a signed endpoint. Errors with
+    /// [`CBAdvError::AuthRequired`] if this Signer has no credentials configured.
     ///
     /// # Arguments
     ///
@@ -181,6 +786,38 @@ impl Signer {
         resource: &str,
         params: &str,
         body: T,
+    ) -> Result<Response> {
+        let body_str = serde_json::to_string(&body).unwrap();
+        let headers = self.get_auth_headers(Method::POST, resource, &body_str)?;
+        self.send_post(resource, params, headers, body_str).await
+    }
+
+    /// Performs a HTTP POST Request against a public endpoint, attaching credentials if
+    /// configured but never requiring them.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource` - A string representing the resource that is being accessed.
+    /// * `params` - A string containing options / parameters for the URL.
+    /// * `body` - An object to send to the URL via POST request.
+    pub async fn post_public<T: Serialize>(
+        &self,
+        resource: &str,
+        params: &str,
+        body: T,
+    ) -> Result<Response> {
+        let body_str = serde_json::to_string(&body).unwrap();
+        let headers = self.get_optional_auth_headers(Method::POST, resource, &body_str)?;
+        self.send_post(resource, params, headers, body_str).await
+    }
+
+    /// Builds the URL for `resource`/`params` and sends a POST request through the layer stack.
+    async fn send_post(
+        &self,
+        resource: &str,
+        params: &str,
+        mut headers: header::HeaderMap,
+        body_str: String,
     ) -> Result<Response> {
         // Add the '?' to the beginning of the parameters if not empty.
         let prefix = match params.is_empty() {
@@ -190,36 +827,125 @@ impl Signer {
 
         // Create the full URL being accessed.
         let target = format!("{}{}", prefix, params);
-        let url = format!("{}{}{}", ROOT_URI, resource, target);
+        let url = format!("{}{}{}", self.base_url, resource, target);
 
-        // Create the signature and submit the request.
-        let body_str = serde_json::to_string(&body).unwrap();
-        let mut headers = self.get_http_signature(Method::POST, resource, &body_str);
         headers.insert("Content-Type", "application/json".parse().unwrap());
 
-        let result = self
-            .client
-            .post(url)
-            .headers(headers)
-            .body(body_str)
-            .send()
-            .await;
+        let ctx = RequestCtx {
+            method: Method::POST,
+            resource: resource.to_string(),
+        };
+        self.run_through_layers(ctx, || {
+            self.client
+                .post(&url)
+                .headers(headers.clone())
+                .body(body_str.clone())
+        })
+        .await
+    }
+}
 
-        match result {
-            Ok(value) => match value.status() {
-                StatusCode::OK => Ok(value),
-                _ => {
-                    let code = format!("Status Code: {}", value.status().as_u16());
-                    match value.text().await {
-                        Ok(text) => Err(CBAdvError::BadStatus(format!("{}, {}", code, text))),
-                        Err(_) => Err(CBAdvError::BadStatus(format!(
-                            "{}, could not parse error message",
-                            code
-                        ))),
+/// Fetches a single page given the cursor to continue from (the starting cursor, or `None`, for
+/// the first page), returning the page's items along with the `has_next`/`cursor` fields the
+/// list endpoint returned.
+type PageFetch<T> = Box<
+    dyn FnMut(
+            Option<String>,
+        ) -> Pin<Box<dyn Future<Output = Result<(Vec<T>, bool, String)>> + Send>>
+        + Send,
+>;
+
+/// A cursor-following `Stream` over a paginated list endpoint.
+///
+/// `Paginator` buffers the current page of items and transparently fetches the next page once
+/// the buffer drains, using the `has_next`/`cursor` fields the API returns. Construct one with
+/// [`Paginator::new`], supplying a closure that performs a single page request, or
+/// [`Paginator::new_with_cursor`] to resume from a previously obtained cursor.
+pub struct Paginator<T> {
+    buffer: VecDeque<T>,
+    cursor: Option<String>,
+    done: bool,
+    fetch: PageFetch<T>,
+    in_flight: Option<Pin<Box<dyn Future<Output = Result<(Vec<T>, bool, String)>> + Send>>>,
+}
+
+// No field is structurally pinned (the in-flight future is already boxed and pinned), so the
+// Paginator can be moved freely and polled through `Pin<&mut Self>` for any `T`.
+impl<T> Unpin for Paginator<T> {}
+
+impl<T> Paginator<T> {
+    /// Creates a new Paginator from a page-fetching closure, starting from the first page.
+    ///
+    /// # Arguments
+    ///
+    /// * `fetch` - Given the previous page's cursor (`None` for the first page), returns the
+    /// next page's items, `has_next`, and `cursor`.
+    pub fn new<F, Fut>(fetch: F) -> Self
+    where
+        F: FnMut(Option<String>) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<(Vec<T>, bool, String)>> + Send + 'static,
+    {
+        Self::new_with_cursor(None, fetch)
+    }
+
+    /// Creates a new Paginator from a page-fetching closure, resuming from a previously
+    /// obtained cursor instead of starting from the first page.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - Cursor to fetch the first page from, e.g. one saved from an earlier stream.
+    /// * `fetch` - Given the previous page's cursor (`cursor` for the first page), returns the
+    /// next page's items, `has_next`, and `cursor`.
+    pub fn new_with_cursor<F, Fut>(cursor: Option<String>, mut fetch: F) -> Self
+    where
+        F: FnMut(Option<String>) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<(Vec<T>, bool, String)>> + Send + 'static,
+    {
+        Self {
+            buffer: VecDeque::new(),
+            cursor,
+            done: false,
+            fetch: Box::new(move |cursor| Box::pin(fetch(cursor))),
+            in_flight: None,
+        }
+    }
+}
+
+impl<T> Stream for Paginator<T> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(item) = self.buffer.pop_front() {
+                return Poll::Ready(Some(Ok(item)));
+            }
+
+            if self.done {
+                return Poll::Ready(None);
+            }
+
+            if self.in_flight.is_none() {
+                let cursor = self.cursor.clone();
+                self.in_flight = Some((self.fetch)(cursor));
+            }
+
+            match self.in_flight.as_mut().unwrap().as_mut().poll(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(result) => {
+                    self.in_flight = None;
+                    match result {
+                        Ok((items, has_next, cursor)) => {
+                            self.buffer.extend(items);
+                            self.cursor = Some(cursor);
+                            self.done = !has_next;
+                        }
+                        Err(error) => {
+                            self.done = true;
+                            return Poll::Ready(Some(Err(error)));
+                        }
                     }
                 }
-            },
-            Err(_) => Err(CBAdvError::Unknown("POST request to API".to_string())),
+            }
         }
     }
 }
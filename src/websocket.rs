@@ -0,0 +1,311 @@
+//! # Coinbase Advanced WebSocket Client
+//!
+//! `websocket` gives access to the real-time market data feed via the Coinbase Advanced Trade
+//! WebSocket API. This allows subscribing to the `ticker`, `level2`, `user`, and `heartbeats`
+//! channels and streaming strongly typed messages as they arrive. The connection automatically
+//! reconnects and re-subscribes to the active channel set if it drops.
+
+use crate::time;
+use crate::utils::{CBAdvError, Result, Signer};
+use futures::stream::Stream;
+use futures::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::sync::{mpsc, Mutex};
+use tokio::time::sleep;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+
+/// WebSocket endpoint for the Advanced Trade market data feed.
+const WS_URI: &str = "wss://advanced-trade-ws.coinbase.com";
+
+/// A channel that can be subscribed to on the Advanced Trade WebSocket feed.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Channel {
+    /// Real-time price updates for the subscribed products.
+    Ticker,
+    /// Order book snapshots and updates for the subscribed products.
+    Level2,
+    /// Authenticated order and account updates for the user associated with the Signer.
+    User,
+    /// Periodic heartbeats, useful for detecting a stalled connection.
+    Heartbeats,
+}
+
+impl Channel {
+    /// Returns the channel name as used by the API.
+    fn as_str(&self) -> &'static str {
+        match self {
+            Channel::Ticker => "ticker",
+            Channel::Level2 => "level2",
+            Channel::User => "user",
+            Channel::Heartbeats => "heartbeats",
+        }
+    }
+}
+
+/// A single channel subscription, scoped to a set of product IDs.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Subscription {
+    pub channel: Channel,
+    pub product_ids: Vec<String>,
+}
+
+/// Frame sent to the API to subscribe/unsubscribe from a channel.
+#[derive(Serialize)]
+struct SubscribeFrame<'a> {
+    r#type: &'a str,
+    product_ids: &'a [String],
+    channel: &'a str,
+    api_key: &'a str,
+    timestamp: &'a str,
+    signature: &'a str,
+}
+
+/// Wraps the events delivered for a single channel message.
+#[allow(dead_code)]
+#[derive(Deserialize, Debug)]
+pub struct ChannelPayload<T> {
+    pub client_id: String,
+    pub timestamp: String,
+    pub sequence_num: i64,
+    pub events: Vec<T>,
+}
+
+/// A ticker update for a single product.
+#[allow(dead_code)]
+#[derive(Deserialize, Debug)]
+pub struct TickerEvent {
+    pub r#type: String,
+    pub tickers: Vec<Ticker>,
+}
+
+/// A single product's ticker price.
+#[allow(dead_code)]
+#[derive(Deserialize, Debug)]
+pub struct Ticker {
+    pub product_id: String,
+    pub price: String,
+    pub volume_24_h: Option<String>,
+}
+
+/// An order book snapshot or update for a single product.
+#[allow(dead_code)]
+#[derive(Deserialize, Debug)]
+pub struct Level2Event {
+    pub r#type: String,
+    pub product_id: String,
+    pub updates: Vec<Level2Update>,
+}
+
+/// A single price level change in an order book.
+#[allow(dead_code)]
+#[derive(Deserialize, Debug)]
+pub struct Level2Update {
+    pub side: String,
+    pub price_level: String,
+    pub new_quantity: String,
+}
+
+/// An authenticated order/account update for the subscribed user.
+#[allow(dead_code)]
+#[derive(Deserialize, Debug)]
+pub struct UserEvent {
+    pub r#type: String,
+    pub orders: Vec<serde_json::Value>,
+}
+
+/// A heartbeat, sent periodically to keep the connection alive.
+#[allow(dead_code)]
+#[derive(Deserialize, Debug)]
+pub struct HeartbeatEvent {
+    pub current_time: String,
+    pub heartbeat_counter: i64,
+}
+
+/// Acknowledges a subscribe/unsubscribe request.
+#[allow(dead_code)]
+#[derive(Deserialize, Debug)]
+pub struct SubscriptionsEvent {
+    pub subscriptions: serde_json::Value,
+}
+
+/// A single message received from the WebSocket feed, tagged by its `channel` field.
+#[allow(dead_code)]
+#[derive(Deserialize, Debug)]
+#[serde(tag = "channel", rename_all = "snake_case")]
+pub enum WsMessage {
+    Ticker(ChannelPayload<TickerEvent>),
+    /// The API tags level2 order-book messages `"l2_data"`, not `"level2"` (the subscribe
+    /// request still uses `"level2"`, see [`Channel::Level2`]).
+    #[serde(rename = "l2_data")]
+    Level2(ChannelPayload<Level2Event>),
+    User(ChannelPayload<UserEvent>),
+    Heartbeats(ChannelPayload<HeartbeatEvent>),
+    Subscriptions(ChannelPayload<SubscriptionsEvent>),
+}
+
+/// Commands sent from `WebSocketClient` handles to the background connection task.
+enum Command {
+    Subscribe(Subscription),
+    Unsubscribe(Subscription),
+}
+
+/// Maintains a WebSocket connection to the Advanced Trade market data feed, yielding a
+/// `Stream` of typed channel messages. Reconnects and re-subscribes to the active channel set
+/// automatically if the connection drops.
+pub struct WebSocketClient {
+    messages: mpsc::UnboundedReceiver<Result<WsMessage>>,
+    commands: mpsc::UnboundedSender<Command>,
+}
+
+impl WebSocketClient {
+    /// Connects to the market data feed and subscribes to the given channels.
+    ///
+    /// # Arguments
+    ///
+    /// * `signer` - A Signer with legacy HMAC credentials, used to sign subscribe/unsubscribe
+    /// frames.
+    /// * `subscriptions` - Channels to subscribe to immediately after connecting.
+    pub async fn connect(signer: Signer, subscriptions: Vec<Subscription>) -> Self {
+        let (message_tx, message_rx) = mpsc::unbounded_channel();
+        let (command_tx, command_rx) = mpsc::unbounded_channel();
+        let active = Arc::new(Mutex::new(subscriptions));
+
+        tokio::spawn(Self::run(signer, active, command_rx, message_tx));
+
+        Self {
+            messages: message_rx,
+            commands: command_tx,
+        }
+    }
+
+    /// Subscribes to an additional channel. Takes effect immediately, and is re-applied on
+    /// reconnect.
+    pub fn subscribe(&self, subscription: Subscription) {
+        let _ = self.commands.send(Command::Subscribe(subscription));
+    }
+
+    /// Unsubscribes from a channel previously passed to `connect` or `subscribe`.
+    pub fn unsubscribe(&self, subscription: Subscription) {
+        let _ = self.commands.send(Command::Unsubscribe(subscription));
+    }
+
+    /// Drives the connection: connects, subscribes to the active channel set, then forwards
+    /// incoming messages and applies subscribe/unsubscribe commands until the connection
+    /// drops, at which point it reconnects and re-subscribes.
+    async fn run(
+        signer: Signer,
+        active: Arc<Mutex<Vec<Subscription>>>,
+        mut commands: mpsc::UnboundedReceiver<Command>,
+        messages: mpsc::UnboundedSender<Result<WsMessage>>,
+    ) {
+        loop {
+            let snapshot = active.lock().await.clone();
+            let mut stream = match Self::connect_and_subscribe(&signer, &snapshot).await {
+                Ok(stream) => stream,
+                Err(error) => {
+                    if messages.send(Err(error)).is_err() {
+                        return;
+                    }
+                    sleep(Duration::from_secs(1)).await;
+                    continue;
+                }
+            };
+
+            loop {
+                tokio::select! {
+                    incoming = stream.next() => {
+                        match incoming {
+                            Some(Ok(Message::Text(text))) => {
+                                let parsed = serde_json::from_str::<WsMessage>(&text)
+                                    .map_err(|_| CBAdvError::BadParse("websocket message".to_string()));
+                                if messages.send(parsed).is_err() {
+                                    return;
+                                }
+                            }
+                            Some(Ok(_)) => {}
+                            Some(Err(_)) | None => break,
+                        }
+                    }
+                    command = commands.recv() => {
+                        match command {
+                            Some(Command::Subscribe(subscription)) => {
+                                active.lock().await.push(subscription.clone());
+                                if let Ok(frame) = Self::build_frame(&signer, "subscribe", &subscription) {
+                                    let _ = stream.send(Message::Text(frame)).await;
+                                }
+                            }
+                            Some(Command::Unsubscribe(subscription)) => {
+                                active.lock().await.retain(|s| *s != subscription);
+                                if let Ok(frame) = Self::build_frame(&signer, "unsubscribe", &subscription) {
+                                    let _ = stream.send(Message::Text(frame)).await;
+                                }
+                            }
+                            None => return,
+                        }
+                    }
+                }
+            }
+
+            // Connection dropped; reconnect and re-subscribe to the active channel set.
+            sleep(Duration::from_secs(1)).await;
+        }
+    }
+
+    /// Opens the WebSocket connection and sends a subscribe frame for each subscription.
+    async fn connect_and_subscribe(
+        signer: &Signer,
+        subscriptions: &[Subscription],
+    ) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>> {
+        let (mut stream, _) = connect_async(WS_URI)
+            .await
+            .map_err(|error| CBAdvError::BadConnection(error.to_string()))?;
+
+        for subscription in subscriptions {
+            let frame = Self::build_frame(signer, "subscribe", subscription)?;
+            stream
+                .send(Message::Text(frame))
+                .await
+                .map_err(|error| CBAdvError::BadConnection(error.to_string()))?;
+        }
+
+        Ok(stream)
+    }
+
+    /// Builds a signed subscribe/unsubscribe frame for a single channel subscription.
+    fn build_frame(signer: &Signer, r#type: &str, subscription: &Subscription) -> Result<String> {
+        let timestamp = time::now().to_string();
+        let signature = signer.get_ws_signature(
+            &timestamp,
+            subscription.channel.as_str(),
+            &subscription.product_ids,
+        )?;
+
+        let frame = SubscribeFrame {
+            r#type,
+            product_ids: &subscription.product_ids,
+            channel: subscription.channel.as_str(),
+            api_key: &signer.api_key,
+            timestamp: &timestamp,
+            signature: &signature,
+        };
+
+        serde_json::to_string(&frame)
+            .map_err(|_| CBAdvError::BadParse("subscribe frame".to_string()))
+    }
+}
+
+impl Stream for WebSocketClient {
+    type Item = Result<WsMessage>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.messages.poll_recv(cx)
+    }
+}